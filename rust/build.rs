@@ -1,31 +1,24 @@
-extern crate bindgen;
-extern crate cmake;
-
 use cmake::Config;
-use std::{env, path::PathBuf};
 
 fn main() {
-    // Run cmake to build nng
+    // Build the C `pgne` core as a static library.
     let dst = Config::new("..")
         .generator("Ninja")
         .define("CMAKE_BUILD_TYPE", "Release")
         .build();
 
-    // Check output of `cargo build --verbose`, should see something like:
-    // -L native=/path/runng/target/debug/build/runng-sys-abc1234/out
-    // That contains output from cmake
     println!("cargo:rustc-link-search=native={}", dst.display());
-    // Tell rustc to use nng static library
     println!("cargo:rustc-link-lib=static=pgne");
 
-    let bindings = bindgen::Builder::default()
-        .header("wrapper.h")
-        // This is needed if use `#include <nng.h>` instead of `#include "path/nng.h"`
-        //.clang_arg("-Inng/src/")
-        .generate()
-        .expect("Unable to generate bindings");
-    let out_path = PathBuf::from(env::var("OUT_DIR").unwrap());
-    bindings
-        .write_to_file(out_path.join("bindings.rs"))
-        .expect("Couldn't write bindings");
+    // Generate and compile the cxx bridge (src/ffi.rs <-> src/ffi.cc) that
+    // replaces the old bindgen-generated bindings.rs.
+    cxx_build::bridge("src/ffi.rs")
+        .file("src/ffi.cc")
+        .include(dst.join("include"))
+        .flag_if_supported("-std=c++17")
+        .compile("pgn-extract-3000-bridge");
+
+    println!("cargo:rerun-if-changed=src/ffi.rs");
+    println!("cargo:rerun-if-changed=src/ffi.cc");
+    println!("cargo:rerun-if-changed=include/ffi.h");
 }
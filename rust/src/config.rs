@@ -0,0 +1,757 @@
+//! Builder for the options the C core understands.
+//!
+//! `Config` owns every field that ends up in a bridged [`StateInfo`], so
+//! callers never touch `StateInfo` (or cxx) directly. Defaults match the
+//! literals the old hand-written `StateInfo` used; each setter corresponds to
+//! one of the option-flag comments that used to sit next to those literals.
+
+use crate::ffi::ffi::StateInfo;
+
+/// Owns the option surface of the underlying `pgne` core and builds the
+/// bridged [`StateInfo`] that drives a single [`crate::process_games`] call.
+#[derive(Debug, Clone)]
+pub struct Config {
+    check_only: bool,
+    verbosity: i32,
+    keep_nags: bool,
+    keep_comments: bool,
+    keep_variations: bool,
+    match_permutations: bool,
+    positional_variations: bool,
+    use_soundex: bool,
+    suppress_duplicates: bool,
+    suppress_originals: bool,
+    fuzzy_match_duplicates: bool,
+    fuzzy_match_depth: i32,
+    add_eco: bool,
+    parsing_eco_file: bool,
+    tag_output_format: i32,
+    eco_level: i32,
+    output_format: i32,
+    max_line_length: i32,
+    use_virtual_hash_table: bool,
+    check_move_bounds: bool,
+    match_only_checkmate: bool,
+    match_only_stalemate: bool,
+    match_only_insufficient_material: bool,
+    keep_move_numbers: bool,
+    keep_results: bool,
+    keep_checks: bool,
+    output_evaluation: bool,
+    keep_broken_games: bool,
+    suppress_redundant_ep_info: bool,
+    json_format: bool,
+    tsv_format: bool,
+    tag_match_anywhere: bool,
+    match_underpromotion: bool,
+    suppress_matched: bool,
+    games_per_file: i32,
+    minply: u32,
+    upper_move_bound: u32,
+    startply: u32,
+    output_ply_limit: i32,
+    quiescence_threshold: i32,
+    drop_ply_number: i32,
+    check_for_repetition: i32,
+    check_for_n_move_rule: i32,
+    output_fen_string: bool,
+    add_fen_comments: bool,
+    add_hashcode_comments: bool,
+    add_position_match_comments: bool,
+    output_plycount: bool,
+    output_total_plycount: bool,
+    add_hashcode_tag: bool,
+    fix_result_tags: bool,
+    fix_tag_strings: bool,
+    add_fen_castling: bool,
+    separate_comment_lines: bool,
+    split_variants: bool,
+    reject_inconsistent_results: bool,
+    allow_null_moves: bool,
+    allow_nested_comments: bool,
+    add_match_tag: bool,
+    add_matchlabel_tag: bool,
+    only_output_wanted_tags: bool,
+    delete_same_setup: bool,
+    lichess_comment_fix: bool,
+    keep_only_commented_games: bool,
+    position_match_comment: String,
+    eco_file: String,
+    fen_comment_pattern: String,
+    drop_comment_pattern: String,
+    line_number_marker: String,
+    output_filename: String,
+    logfile: String,
+    duplicate_file: String,
+    non_matching_file: String,
+}
+
+impl Default for Config {
+    fn default() -> Self {
+        Config {
+            check_only: false,
+            verbosity: 2,
+            keep_nags: true,
+            keep_comments: true,
+            keep_variations: true,
+            match_permutations: true,
+            positional_variations: false,
+            use_soundex: false,
+            suppress_duplicates: false,
+            suppress_originals: false,
+            fuzzy_match_duplicates: false,
+            fuzzy_match_depth: 0,
+            add_eco: false,
+            parsing_eco_file: false,
+            tag_output_format: 0, // TagOutputForm_ALL_TAGS
+            eco_level: 0,         // EcoDivision_DONT_DIVIDE
+            output_format: 0,     // OutputFormat_SAN
+            max_line_length: 80,
+            use_virtual_hash_table: false,
+            check_move_bounds: false,
+            match_only_checkmate: false,
+            match_only_stalemate: false,
+            match_only_insufficient_material: false,
+            keep_move_numbers: true,
+            keep_results: true,
+            keep_checks: true,
+            output_evaluation: false,
+            keep_broken_games: false,
+            suppress_redundant_ep_info: false,
+            json_format: false,
+            tsv_format: false,
+            tag_match_anywhere: false,
+            match_underpromotion: false,
+            suppress_matched: false,
+            games_per_file: 0,
+            minply: 0,
+            upper_move_bound: 10_000,
+            startply: 1,
+            output_ply_limit: -1,
+            quiescence_threshold: 0,
+            drop_ply_number: 0,
+            check_for_repetition: 0,
+            check_for_n_move_rule: 0,
+            output_fen_string: false,
+            add_fen_comments: false,
+            add_hashcode_comments: false,
+            add_position_match_comments: false,
+            output_plycount: false,
+            output_total_plycount: false,
+            add_hashcode_tag: false,
+            fix_result_tags: false,
+            fix_tag_strings: false,
+            add_fen_castling: false,
+            separate_comment_lines: false,
+            split_variants: false,
+            reject_inconsistent_results: false,
+            allow_null_moves: false,
+            allow_nested_comments: false,
+            add_match_tag: false,
+            add_matchlabel_tag: false,
+            only_output_wanted_tags: false,
+            delete_same_setup: false,
+            lichess_comment_fix: false,
+            keep_only_commented_games: false,
+            position_match_comment: String::from("Match"),
+            eco_file: String::from("eco.pgn"),
+            fen_comment_pattern: String::new(),
+            drop_comment_pattern: String::new(),
+            line_number_marker: String::new(),
+            output_filename: String::new(),
+            logfile: String::new(),
+            duplicate_file: String::new(),
+            non_matching_file: String::new(),
+        }
+    }
+}
+
+impl Config {
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// `-r`: only check games for errors, don't output them.
+    pub fn check_only(&mut self, value: bool) -> &mut Self {
+        self.check_only = value;
+        self
+    }
+
+    /// `-s` / `--quiet`: verbosity level.
+    pub fn verbosity(&mut self, value: i32) -> &mut Self {
+        self.verbosity = value;
+        self
+    }
+
+    /// `-N`: keep NAGs in the output.
+    pub fn keep_nags(&mut self, value: bool) -> &mut Self {
+        self.keep_nags = value;
+        self
+    }
+
+    /// `-C`: keep comments in the output.
+    pub fn keep_comments(&mut self, value: bool) -> &mut Self {
+        self.keep_comments = value;
+        self
+    }
+
+    /// `-V`: keep variations in the output.
+    pub fn keep_variations(&mut self, value: bool) -> &mut Self {
+        self.keep_variations = value;
+        self
+    }
+
+    /// `-v`: try matching positions against all permutations of a move.
+    pub fn match_permutations(&mut self, value: bool) -> &mut Self {
+        self.match_permutations = value;
+        self
+    }
+
+    /// `-x`: search for matching positions in variations, not just the
+    /// mainline.
+    pub fn positional_variations(&mut self, value: bool) -> &mut Self {
+        self.positional_variations = value;
+        self
+    }
+
+    /// `-S`: use a soundex match on player names.
+    pub fn use_soundex(&mut self, value: bool) -> &mut Self {
+        self.use_soundex = value;
+        self
+    }
+
+    /// `-D`: suppress duplicate games in the output.
+    pub fn suppress_duplicates(&mut self, value: bool) -> &mut Self {
+        self.suppress_duplicates = value;
+        self
+    }
+
+    /// `-U`: suppress the original of a set of duplicates, keeping only later
+    /// copies.
+    pub fn suppress_originals(&mut self, value: bool) -> &mut Self {
+        self.suppress_originals = value;
+        self
+    }
+
+    /// `--fuzzy`: treat games as duplicates if they match up to `depth`
+    /// plies, rather than requiring an exact match.
+    pub fn fuzzy(&mut self, depth: i32) -> &mut Self {
+        self.fuzzy_match_duplicates = true;
+        self.fuzzy_match_depth = depth;
+        self
+    }
+
+    /// `-e`: add ECO classification tags to the output.
+    pub fn add_eco(&mut self, value: bool) -> &mut Self {
+        self.add_eco = value;
+        self
+    }
+
+    /// `-e`: the named file is an ECO file being parsed, not PGN being
+    /// classified.
+    pub fn parsing_eco_file(&mut self, value: bool) -> &mut Self {
+        self.parsing_eco_file = value;
+        self
+    }
+
+    /// `--json`: emit JSON instead of PGN.
+    pub fn json_format(&mut self, value: bool) -> &mut Self {
+        self.json_format = value;
+        self
+    }
+
+    /// `--tsv`: emit tab-separated output instead of PGN.
+    pub fn tsv_format(&mut self, value: bool) -> &mut Self {
+        self.tsv_format = value;
+        self
+    }
+
+    /// `-Z`: use the virtual hash table duplicate-detection strategy.
+    pub fn use_virtual_hash_table(&mut self, value: bool) -> &mut Self {
+        self.use_virtual_hash_table = value;
+        self
+    }
+
+    /// `-b`: only keep games whose move count falls within `minply`/`maxply`.
+    pub fn check_move_bounds(&mut self, value: bool) -> &mut Self {
+        self.check_move_bounds = value;
+        self
+    }
+
+    /// `-M`: only keep games that end in checkmate.
+    pub fn match_only_checkmate(&mut self, value: bool) -> &mut Self {
+        self.match_only_checkmate = value;
+        self
+    }
+
+    /// `--stalemate`: only keep games that end in stalemate.
+    pub fn match_only_stalemate(&mut self, value: bool) -> &mut Self {
+        self.match_only_stalemate = value;
+        self
+    }
+
+    /// `--insufficient`: only keep games ending with insufficient mating
+    /// material.
+    pub fn match_only_insufficient_material(&mut self, value: bool) -> &mut Self {
+        self.match_only_insufficient_material = value;
+        self
+    }
+
+    /// `--nomovenumbers`: strip move numbers from the output (pass `false`).
+    pub fn keep_move_numbers(&mut self, value: bool) -> &mut Self {
+        self.keep_move_numbers = value;
+        self
+    }
+
+    /// `--noresults`: strip result tags from the output (pass `false`).
+    pub fn keep_results(&mut self, value: bool) -> &mut Self {
+        self.keep_results = value;
+        self
+    }
+
+    /// `--nochecks`: strip check/checkmate `+`/`#` suffixes (pass `false`).
+    pub fn keep_checks(&mut self, value: bool) -> &mut Self {
+        self.keep_checks = value;
+        self
+    }
+
+    /// `--evaluation`: include engine evaluation comments in the output.
+    pub fn output_evaluation(&mut self, value: bool) -> &mut Self {
+        self.output_evaluation = value;
+        self
+    }
+
+    /// `--keepbroken`: keep games that fail to parse fully, up to the point
+    /// they broke.
+    pub fn keep_broken_games(&mut self, value: bool) -> &mut Self {
+        self.keep_broken_games = value;
+        self
+    }
+
+    /// `--nofauxep`: suppress redundant en passant annotations.
+    pub fn suppress_redundant_ep_info(&mut self, value: bool) -> &mut Self {
+        self.suppress_redundant_ep_info = value;
+        self
+    }
+
+    /// `--tagsubstr`: match tag values anywhere in the string, not just as a
+    /// whole-value match.
+    pub fn tag_match_anywhere(&mut self, value: bool) -> &mut Self {
+        self.tag_match_anywhere = value;
+        self
+    }
+
+    /// `--underpromotion`: allow underpromotions when matching moves.
+    pub fn match_underpromotion(&mut self, value: bool) -> &mut Self {
+        self.match_underpromotion = value;
+        self
+    }
+
+    /// `--suppressmatched`: suppress games that matched, keeping only the
+    /// non-matching ones.
+    pub fn suppress_matched(&mut self, value: bool) -> &mut Self {
+        self.suppress_matched = value;
+        self
+    }
+
+    /// `-#`: split output into files of this many games each (`0` disables
+    /// splitting).
+    pub fn games_per_file(&mut self, value: i32) -> &mut Self {
+        self.games_per_file = value;
+        self
+    }
+
+    /// Lower bound on ply count a game must reach to be considered.
+    ///
+    /// Implies `-b` (`check_move_bounds`): the C core only applies
+    /// `minply`/`maxply` when that flag is on, so setting either bound here
+    /// turns it on rather than leaving it a silent no-op.
+    pub fn minply(&mut self, value: u32) -> &mut Self {
+        self.minply = value;
+        self.check_move_bounds = true;
+        self
+    }
+
+    /// Upper bound on ply count a game must stay within to be considered.
+    ///
+    /// Implies `-b` (`check_move_bounds`); see [`Config::minply`].
+    pub fn maxply(&mut self, value: u32) -> &mut Self {
+        self.upper_move_bound = value;
+        self.check_move_bounds = true;
+        self
+    }
+
+    /// `--startply`: ply to start output from within a matched game.
+    pub fn startply(&mut self, value: u32) -> &mut Self {
+        self.startply = value;
+        self
+    }
+
+    /// `--plylimit`: truncate output after this many plies (`-1` for no limit).
+    pub fn plylimit(&mut self, value: i32) -> &mut Self {
+        self.output_ply_limit = value;
+        self
+    }
+
+    /// `--stable`: only output positions once the evaluation has been stable
+    /// for this many plies.
+    pub fn quiescence_threshold(&mut self, value: i32) -> &mut Self {
+        self.quiescence_threshold = value;
+        self
+    }
+
+    /// `--dropply`: drop this many plies from the start of the output.
+    pub fn drop_ply_number(&mut self, value: i32) -> &mut Self {
+        self.drop_ply_number = value;
+        self
+    }
+
+    /// `--repetition`: flag games containing an N-fold repetition.
+    pub fn repetition(&mut self, value: i32) -> &mut Self {
+        self.check_for_repetition = value;
+        self
+    }
+
+    /// `--fifty` / `--seventyfive`: flag games eligible for the N-move rule.
+    pub fn fifty(&mut self, value: i32) -> &mut Self {
+        self.check_for_n_move_rule = value;
+        self
+    }
+
+    /// Output each position as a FEN string instead of (or alongside) SAN.
+    pub fn output_fen_string(&mut self, value: bool) -> &mut Self {
+        self.output_fen_string = value;
+        self
+    }
+
+    /// `--fencomments`: annotate each move with a FEN comment.
+    pub fn add_fen_comments(&mut self, value: bool) -> &mut Self {
+        self.add_fen_comments = value;
+        self
+    }
+
+    /// `--hashcomments`: annotate each move with a position hashcode comment.
+    pub fn add_hashcode_comments(&mut self, value: bool) -> &mut Self {
+        self.add_hashcode_comments = value;
+        self
+    }
+
+    /// `--markmatches`: annotate matched positions with `position_match_comment`.
+    pub fn markmatches(&mut self, value: bool) -> &mut Self {
+        self.add_position_match_comments = value;
+        self
+    }
+
+    /// `--plycount`: add a `PlyCount` tag to each game.
+    pub fn output_plycount(&mut self, value: bool) -> &mut Self {
+        self.output_plycount = value;
+        self
+    }
+
+    /// `--totalplycount`: add a `PlyCount` tag counting from the start of
+    /// the game, ignoring any `--startply`/`--dropply` trimming.
+    pub fn output_total_plycount(&mut self, value: bool) -> &mut Self {
+        self.output_total_plycount = value;
+        self
+    }
+
+    /// `--addhashcode`: add a `HashCode` tag to each game.
+    pub fn add_hashcode_tag(&mut self, value: bool) -> &mut Self {
+        self.add_hashcode_tag = value;
+        self
+    }
+
+    /// `--fixresulttags`: rewrite result tags to match the actual outcome.
+    pub fn fixresulttags(&mut self, value: bool) -> &mut Self {
+        self.fix_result_tags = value;
+        self
+    }
+
+    /// `--fixtagstrings`: normalize malformed tag string escaping.
+    pub fn fix_tag_strings(&mut self, value: bool) -> &mut Self {
+        self.fix_tag_strings = value;
+        self
+    }
+
+    /// `--addfencastling`: add castling rights to generated FEN strings.
+    pub fn add_fen_castling(&mut self, value: bool) -> &mut Self {
+        self.add_fen_castling = value;
+        self
+    }
+
+    /// `--commentlines`: output each comment on its own line.
+    pub fn separate_comment_lines(&mut self, value: bool) -> &mut Self {
+        self.separate_comment_lines = value;
+        self
+    }
+
+    /// `--separatevariants`: output each variation as its own game.
+    pub fn split_variants(&mut self, value: bool) -> &mut Self {
+        self.split_variants = value;
+        self
+    }
+
+    /// `--nobadresults`: reject games whose result tag and move list disagree.
+    pub fn nobadresults(&mut self, value: bool) -> &mut Self {
+        self.reject_inconsistent_results = value;
+        self
+    }
+
+    /// `--allownullmoves`: accept null moves (`--`) while parsing.
+    pub fn allow_null_moves(&mut self, value: bool) -> &mut Self {
+        self.allow_null_moves = value;
+        self
+    }
+
+    /// `--nestedcomments`: accept nested `{}` comments while parsing.
+    pub fn allow_nested_comments(&mut self, value: bool) -> &mut Self {
+        self.allow_nested_comments = value;
+        self
+    }
+
+    /// `--addmatchtag`: add a tag recording that this game matched.
+    pub fn add_match_tag(&mut self, value: bool) -> &mut Self {
+        self.add_match_tag = value;
+        self
+    }
+
+    /// `--addlabeltag`: add a tag carrying the matching label for this game.
+    pub fn add_matchlabel_tag(&mut self, value: bool) -> &mut Self {
+        self.add_matchlabel_tag = value;
+        self
+    }
+
+    /// `--xroster`: only output the tags named on the roster, not the full
+    /// tag set.
+    pub fn only_output_wanted_tags(&mut self, value: bool) -> &mut Self {
+        self.only_output_wanted_tags = value;
+        self
+    }
+
+    /// `--deletesamesetup`: drop games that share a starting setup with one
+    /// already output.
+    pub fn delete_same_setup(&mut self, value: bool) -> &mut Self {
+        self.delete_same_setup = value;
+        self
+    }
+
+    /// `--lichesscommentfix`: work around lichess's non-standard comment
+    /// escaping.
+    pub fn lichess_comment_fix(&mut self, value: bool) -> &mut Self {
+        self.lichess_comment_fix = value;
+        self
+    }
+
+    /// `--only_commented_games`: drop games with no comments at all.
+    pub fn keep_only_commented_games(&mut self, value: bool) -> &mut Self {
+        self.keep_only_commented_games = value;
+        self
+    }
+
+    /// Comment text used when `--markmatches` is set.
+    pub fn position_match_comment(&mut self, value: impl Into<String>) -> &mut Self {
+        self.position_match_comment = value.into();
+        self
+    }
+
+    /// `-e`: path to the ECO classification file.
+    pub fn eco_file(&mut self, value: impl Into<String>) -> &mut Self {
+        self.eco_file = value.into();
+        self
+    }
+
+    /// `-Fpattern`: only output comments matching this pattern.
+    pub fn fen_comment_pattern(&mut self, value: impl Into<String>) -> &mut Self {
+        self.fen_comment_pattern = value.into();
+        self
+    }
+
+    /// `--dropbefore`: drop comments matching this pattern.
+    pub fn drop_comment_pattern(&mut self, value: impl Into<String>) -> &mut Self {
+        self.drop_comment_pattern = value.into();
+        self
+    }
+
+    /// `--linenumbers`: prefix each output line with this marker.
+    pub fn line_number_marker(&mut self, value: impl Into<String>) -> &mut Self {
+        self.line_number_marker = value.into();
+        self
+    }
+
+    /// `-o` / `-a`: output file path. Empty means stdout.
+    pub fn output_filename(&mut self, value: impl Into<String>) -> &mut Self {
+        self.output_filename = value.into();
+        self
+    }
+
+    /// `-l`: log file path. Empty means stderr.
+    pub fn logfile(&mut self, value: impl Into<String>) -> &mut Self {
+        self.logfile = value.into();
+        self
+    }
+
+    /// `-d`: path to write duplicate games to. Empty discards them.
+    pub fn duplicate_file(&mut self, value: impl Into<String>) -> &mut Self {
+        self.duplicate_file = value.into();
+        self
+    }
+
+    /// `-n`: path to write non-matching games to. Empty discards them.
+    pub fn non_matching_file(&mut self, value: impl Into<String>) -> &mut Self {
+        self.non_matching_file = value.into();
+        self
+    }
+
+    /// Builds the bridged [`StateInfo`] the C core consumes. The returned
+    /// value owns its own copies of every string, so it does not borrow from
+    /// `self`.
+    pub fn as_state_info(&self) -> StateInfo {
+        StateInfo {
+            skipping_current_game: false,
+            check_only: self.check_only,
+            verbosity: self.verbosity,
+            keep_nags: self.keep_nags,
+            keep_comments: self.keep_comments,
+            keep_variations: self.keep_variations,
+            match_permutations: self.match_permutations,
+            positional_variations: self.positional_variations,
+            use_soundex: self.use_soundex,
+            suppress_duplicates: self.suppress_duplicates,
+            suppress_originals: self.suppress_originals,
+            fuzzy_match_duplicates: self.fuzzy_match_duplicates,
+            fuzzy_match_depth: self.fuzzy_match_depth,
+            add_eco: self.add_eco,
+            parsing_eco_file: self.parsing_eco_file,
+            tag_output_format: self.tag_output_format,
+            eco_level: self.eco_level,
+            output_format: self.output_format,
+            max_line_length: self.max_line_length,
+            use_virtual_hash_table: self.use_virtual_hash_table,
+            check_move_bounds: self.check_move_bounds,
+            match_only_checkmate: self.match_only_checkmate,
+            match_only_stalemate: self.match_only_stalemate,
+            match_only_insufficient_material: self.match_only_insufficient_material,
+            keep_move_numbers: self.keep_move_numbers,
+            keep_results: self.keep_results,
+            keep_checks: self.keep_checks,
+            output_evaluation: self.output_evaluation,
+            keep_broken_games: self.keep_broken_games,
+            suppress_redundant_ep_info: self.suppress_redundant_ep_info,
+            json_format: self.json_format,
+            tsv_format: self.tsv_format,
+            tag_match_anywhere: self.tag_match_anywhere,
+            match_underpromotion: self.match_underpromotion,
+            suppress_matched: self.suppress_matched,
+            games_per_file: self.games_per_file,
+            minply: self.minply,
+            upper_move_bound: self.upper_move_bound,
+            startply: self.startply,
+            output_ply_limit: self.output_ply_limit,
+            quiescence_threshold: self.quiescence_threshold,
+            drop_ply_number: self.drop_ply_number,
+            check_for_repetition: self.check_for_repetition,
+            check_for_n_move_rule: self.check_for_n_move_rule,
+            output_fen_string: self.output_fen_string,
+            add_fen_comments: self.add_fen_comments,
+            add_hashcode_comments: self.add_hashcode_comments,
+            add_position_match_comments: self.add_position_match_comments,
+            output_plycount: self.output_plycount,
+            output_total_plycount: self.output_total_plycount,
+            add_hashcode_tag: self.add_hashcode_tag,
+            fix_result_tags: self.fix_result_tags,
+            fix_tag_strings: self.fix_tag_strings,
+            add_fen_castling: self.add_fen_castling,
+            separate_comment_lines: self.separate_comment_lines,
+            split_variants: self.split_variants,
+            reject_inconsistent_results: self.reject_inconsistent_results,
+            allow_null_moves: self.allow_null_moves,
+            allow_nested_comments: self.allow_nested_comments,
+            add_match_tag: self.add_match_tag,
+            add_matchlabel_tag: self.add_matchlabel_tag,
+            only_output_wanted_tags: self.only_output_wanted_tags,
+            delete_same_setup: self.delete_same_setup,
+            lichess_comment_fix: self.lichess_comment_fix,
+            keep_only_commented_games: self.keep_only_commented_games,
+            position_match_comment: self.position_match_comment.clone(),
+            eco_file: self.eco_file.clone(),
+            fen_comment_pattern: self.fen_comment_pattern.clone(),
+            drop_comment_pattern: self.drop_comment_pattern.clone(),
+            line_number_marker: self.line_number_marker.clone(),
+            output_filename: self.output_filename.clone(),
+            logfile: self.logfile.clone(),
+            duplicate_file: self.duplicate_file.clone(),
+            non_matching_file: self.non_matching_file.clone(),
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn defaults_match_the_pre_bridge_literals() {
+        let state = Config::new().as_state_info();
+        assert!(state.keep_nags);
+        assert!(state.keep_comments);
+        assert!(state.keep_variations);
+        assert!(state.match_permutations);
+        assert!(!state.check_move_bounds);
+        assert_eq!(state.verbosity, 2);
+        assert_eq!(state.max_line_length, 80);
+        assert_eq!(state.output_ply_limit, -1);
+        assert_eq!(state.upper_move_bound, 10_000);
+        assert_eq!(state.position_match_comment, "Match");
+        assert_eq!(state.eco_file, "eco.pgn");
+    }
+
+    #[test]
+    fn minply_sets_lower_bound_and_turns_on_check_move_bounds() {
+        let state = Config::new().minply(5).as_state_info();
+        assert_eq!(state.minply, 5);
+        assert!(state.check_move_bounds);
+    }
+
+    #[test]
+    fn maxply_sets_upper_bound_and_turns_on_check_move_bounds() {
+        let state = Config::new().maxply(40).as_state_info();
+        assert_eq!(state.upper_move_bound, 40);
+        assert!(state.check_move_bounds);
+    }
+
+    #[test]
+    fn fuzzy_sets_depth_and_flag_together() {
+        let state = Config::new().fuzzy(3).as_state_info();
+        assert!(state.fuzzy_match_duplicates);
+        assert_eq!(state.fuzzy_match_depth, 3);
+    }
+
+    #[test]
+    fn boolean_setters_flip_their_own_field_only() {
+        let baseline = Config::new().as_state_info();
+        let state = Config::new().use_soundex(true).as_state_info();
+        assert!(state.use_soundex);
+        assert_eq!(state.suppress_duplicates, baseline.suppress_duplicates);
+        assert_eq!(state.json_format, baseline.json_format);
+    }
+
+    #[test]
+    fn string_setters_are_reflected_and_owned() {
+        let state = Config::new()
+            .output_filename("out.pgn")
+            .eco_file("custom.eco")
+            .as_state_info();
+        assert_eq!(state.output_filename, "out.pgn");
+        assert_eq!(state.eco_file, "custom.eco");
+    }
+
+    #[test]
+    fn setters_chain_via_mut_self() {
+        let mut config = Config::new();
+        config.json_format(true).tsv_format(false).startply(2);
+        let state = config.as_state_info();
+        assert!(state.json_format);
+        assert!(!state.tsv_format);
+        assert_eq!(state.startply, 2);
+    }
+}
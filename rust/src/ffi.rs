@@ -0,0 +1,113 @@
+//! Safe bridge onto the C `pgne` core.
+//!
+//! The bridge replaces the old bindgen-generated `bindings.rs`: instead of a
+//! raw `StateInfo` full of `*const c_char` fields that the caller had to keep
+//! alive by hand, `StateInfo` here is a shared struct whose string fields are
+//! owned `String`s. cxx generates the C++-side struct and the glue that
+//! copies across the boundary, so there is no pointer for Rust to dangle and
+//! no `unsafe` required of callers.
+
+#[cxx::bridge(namespace = "pgne")]
+pub mod ffi {
+    /// Mirrors the C `StateInfo` struct (see `pgn-extract`'s `defs.h`), trimmed to
+    /// the fields the Rust side actually drives today. String fields are owned
+    /// here and copied into the C++ struct for the lifetime of a single
+    /// `process_games` call, rather than living on as raw pointers afterwards.
+    #[derive(Debug, Clone)]
+    struct StateInfo {
+        skipping_current_game: bool,
+        check_only: bool,
+        verbosity: i32,
+        keep_nags: bool,
+        keep_comments: bool,
+        keep_variations: bool,
+        match_permutations: bool,
+        positional_variations: bool,
+        use_soundex: bool,
+        suppress_duplicates: bool,
+        suppress_originals: bool,
+        fuzzy_match_duplicates: bool,
+        fuzzy_match_depth: i32,
+        add_eco: bool,
+        parsing_eco_file: bool,
+        tag_output_format: i32,
+        eco_level: i32,
+        output_format: i32,
+        max_line_length: i32,
+        use_virtual_hash_table: bool,
+        check_move_bounds: bool,
+        match_only_checkmate: bool,
+        match_only_stalemate: bool,
+        match_only_insufficient_material: bool,
+        keep_move_numbers: bool,
+        keep_results: bool,
+        keep_checks: bool,
+        output_evaluation: bool,
+        keep_broken_games: bool,
+        suppress_redundant_ep_info: bool,
+        json_format: bool,
+        tsv_format: bool,
+        tag_match_anywhere: bool,
+        match_underpromotion: bool,
+        suppress_matched: bool,
+        games_per_file: i32,
+        minply: u32,
+        upper_move_bound: u32,
+        startply: u32,
+        output_ply_limit: i32,
+        quiescence_threshold: i32,
+        drop_ply_number: i32,
+        check_for_repetition: i32,
+        check_for_n_move_rule: i32,
+        output_fen_string: bool,
+        add_fen_comments: bool,
+        add_hashcode_comments: bool,
+        add_position_match_comments: bool,
+        output_plycount: bool,
+        output_total_plycount: bool,
+        add_hashcode_tag: bool,
+        fix_result_tags: bool,
+        fix_tag_strings: bool,
+        add_fen_castling: bool,
+        separate_comment_lines: bool,
+        split_variants: bool,
+        reject_inconsistent_results: bool,
+        allow_null_moves: bool,
+        allow_nested_comments: bool,
+        add_match_tag: bool,
+        add_matchlabel_tag: bool,
+        only_output_wanted_tags: bool,
+        delete_same_setup: bool,
+        lichess_comment_fix: bool,
+        keep_only_commented_games: bool,
+        position_match_comment: String,
+        eco_file: String,
+        fen_comment_pattern: String,
+        drop_comment_pattern: String,
+        line_number_marker: String,
+        output_filename: String,
+        logfile: String,
+        duplicate_file: String,
+        non_matching_file: String,
+    }
+
+    unsafe extern "C++" {
+        include!("pgn-extract-3000/include/ffi.h");
+
+        /// Runs the C core over `input_path` with the given configuration and
+        /// writes matching games to `state.output_filename` (stdout if empty).
+        /// Returns the number of games processed, or `-1` if the input or any
+        /// configured output, log, duplicate or non-matching file cannot be
+        /// opened.
+        ///
+        /// `state` is borrowed for the duration of the call, so every string
+        /// it carries only needs to outlive this one invocation.
+        fn process_games(state: &StateInfo, input_path: &str) -> i32;
+
+        /// Like `process_games`, but redirects the core's output into an
+        /// in-memory buffer instead of `state.output_filename`, forcing
+        /// `json_format` on for the duration of the call so the buffer comes
+        /// back as newline-delimited JSON `crate::game` can parse.
+        fn capture_output(state: &StateInfo, input_path: &str) -> Result<String>;
+    }
+}
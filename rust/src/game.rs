@@ -0,0 +1,162 @@
+//! Owned, Rust-native representation of a parsed game.
+
+use std::collections::BTreeMap;
+
+use serde::{Deserialize, Serialize};
+
+/// A single parsed game: its tag roster, move list, result, and any match
+/// label added by the matching options on [`crate::Config`].
+///
+/// Carries the same data the CLI's `--json` output does -- a `Game` is parsed
+/// from the same newline-delimited JSON the C core writes for the CLI -- though the
+/// `Serialize` impl here is Rust-idiomatic rather than byte-for-byte
+/// identical to the core's own JSON (e.g. `tags` as pairs, not a map).
+#[derive(Debug, Clone, Serialize)]
+pub struct Game {
+    pub tags: Vec<(String, String)>,
+    pub moves: Vec<String>,
+    pub result: String,
+    pub match_label: Option<String>,
+}
+
+/// The shape of one line of the C core's `--json` output. Kept separate from
+/// [`Game`] because the wire format uses a tag map and an empty string for
+/// "no match label", neither of which is the representation callers want.
+#[derive(Debug, Deserialize)]
+struct RawGame {
+    tags: BTreeMap<String, String>,
+    moves: Vec<String>,
+    result: String,
+    #[serde(default)]
+    match_label: String,
+}
+
+impl From<RawGame> for Game {
+    fn from(raw: RawGame) -> Self {
+        Game {
+            tags: raw.tags.into_iter().collect(),
+            moves: raw.moves,
+            result: raw.result,
+            match_label: (!raw.match_label.is_empty()).then_some(raw.match_label),
+        }
+    }
+}
+
+/// Parses the core's newline-delimited `--json` output into [`Game`] values,
+/// one per non-empty line.
+pub(crate) fn parse_games(output: &str) -> Vec<Result<Game, PgnError>> {
+    output
+        .lines()
+        .filter(|line| !line.trim().is_empty())
+        .map(|line| {
+            serde_json::from_str::<RawGame>(line)
+                .map(Game::from)
+                .map_err(PgnError::from)
+        })
+        .collect()
+}
+
+/// Errors [`crate::process_games`] can return.
+#[derive(Debug)]
+pub enum PgnError {
+    /// The input path contained non-UTF-8 bytes, which the C core requires.
+    InvalidPath(std::path::PathBuf),
+    /// The C core failed to process the file; the message comes from the
+    /// underlying `std::runtime_error`.
+    Core(String),
+    /// A line of the core's `--json` output did not parse.
+    Json(serde_json::Error),
+}
+
+impl std::fmt::Display for PgnError {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            PgnError::InvalidPath(path) => {
+                write!(f, "input path {} is not valid UTF-8", path.display())
+            }
+            PgnError::Core(message) => write!(f, "{message}"),
+            PgnError::Json(err) => write!(f, "malformed --json output: {err}"),
+        }
+    }
+}
+
+impl std::error::Error for PgnError {}
+
+impl From<cxx::Exception> for PgnError {
+    fn from(exception: cxx::Exception) -> Self {
+        PgnError::Core(exception.what().to_string())
+    }
+}
+
+impl From<serde_json::Error> for PgnError {
+    fn from(error: serde_json::Error) -> Self {
+        PgnError::Json(error)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn raw_game(match_label: &str) -> RawGame {
+        RawGame {
+            tags: BTreeMap::from([("White".to_string(), "Carlsen, M.".to_string())]),
+            moves: vec!["e4".to_string(), "e5".to_string()],
+            result: "1-0".to_string(),
+            match_label: match_label.to_string(),
+        }
+    }
+
+    #[test]
+    fn empty_match_label_becomes_none() {
+        let game = Game::from(raw_game(""));
+        assert_eq!(game.match_label, None);
+    }
+
+    #[test]
+    fn non_empty_match_label_is_preserved() {
+        let game = Game::from(raw_game("opening-trap"));
+        assert_eq!(game.match_label.as_deref(), Some("opening-trap"));
+    }
+
+    #[test]
+    fn tags_round_trip_as_pairs() {
+        let game = Game::from(raw_game(""));
+        assert_eq!(
+            game.tags,
+            vec![("White".to_string(), "Carlsen, M.".to_string())]
+        );
+    }
+
+    #[test]
+    fn parse_games_splits_on_newlines_and_skips_blanks() {
+        let output = format!(
+            "{}\n\n{}\n",
+            serde_json::to_string(&serde_json::json!({
+                "tags": {"White": "Carlsen, M."},
+                "moves": ["e4", "e5"],
+                "result": "1-0",
+                "match_label": "",
+            }))
+            .unwrap(),
+            serde_json::to_string(&serde_json::json!({
+                "tags": {"White": "Caruana, F."},
+                "moves": ["d4"],
+                "result": "*",
+                "match_label": "",
+            }))
+            .unwrap(),
+        );
+
+        let games = parse_games(&output);
+        assert_eq!(games.len(), 2);
+        assert!(games.iter().all(Result::is_ok));
+    }
+
+    #[test]
+    fn parse_games_reports_malformed_lines() {
+        let games = parse_games("not json\n");
+        assert_eq!(games.len(), 1);
+        assert!(games[0].is_err());
+    }
+}
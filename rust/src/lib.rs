@@ -0,0 +1,62 @@
+//! Library API for pgn-extract-3000.
+//!
+//! The binary (`main.rs`) is a thin CLI shell over this crate: both drive the
+//! C `pgne` core through the same [`Config`] and [`ffi`] bridge, but
+//! [`process_games`] captures parsed games as owned [`Game`] values instead
+//! of writing them to a file, so analysis tools can depend on this crate
+//! directly rather than shelling out.
+
+#![warn(clippy::all, clippy::pedantic)]
+
+mod config;
+mod ffi;
+mod game;
+
+pub use config::Config;
+pub use game::{Game, PgnError};
+
+use std::path::Path;
+
+/// Runs the C core over `input` with `config`, writing matching games to
+/// `config`'s output file (stdout if unset) and returning the number of
+/// games processed. This is what the CLI uses: it never materializes
+/// [`Game`] values, since the C core streams its output straight to the file.
+pub fn write_games(input: &Path, config: &Config) -> Result<i32, PgnError> {
+    let Some(input_path) = input.to_str() else {
+        return Err(PgnError::InvalidPath(input.to_path_buf()));
+    };
+
+    let state = config.as_state_info();
+    let processed = ffi::ffi::process_games(&state, input_path);
+    if processed < 0 {
+        return Err(PgnError::Core(format!(
+            "failed to open input {} or one of the configured output files",
+            input.display()
+        )));
+    }
+    Ok(processed)
+}
+
+/// Runs the C core over `input` with `config` and returns an iterator over
+/// the games it parses, in file order.
+///
+/// Unlike the CLI, this never writes to `config`'s output file: the core's
+/// output is captured in memory (with `json_format` forced on and
+/// `tsv_format` forced off, regardless of `config`) and parsed line-by-line
+/// into owned [`Game`] values, so a caller can filter, collect, or
+/// re-serialize them without shelling back out to `pgn-extract-3000`.
+pub fn process_games(
+    input: &Path,
+    config: &Config,
+) -> impl Iterator<Item = Result<Game, PgnError>> {
+    let Some(input_path) = input.to_str() else {
+        let error = PgnError::InvalidPath(input.to_path_buf());
+        return Box::new(std::iter::once(Err(error))) as Box<dyn Iterator<Item = _>>;
+    };
+
+    let state = config.as_state_info();
+    match ffi::ffi::capture_output(&state, input_path) {
+        Ok(output) => Box::new(game::parse_games(&output).into_iter()),
+        Err(exception) => Box::new(std::iter::once(Err(PgnError::from(exception)))),
+    }
+}